@@ -1,6 +1,13 @@
+mod model;
+mod sinks;
+mod state;
+
 use anyhow::{Context, Result};
 use clap::Parser;
-use serde::{Deserialize, Serialize};
+use model::Silence;
+use sinks::{OutputKind, ReportSink, SlackSink, TelegramSink, WebhookSink};
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Parser, Debug)]
 #[command(name = "alertmanager-silences-slack-reporter")]
@@ -9,77 +16,118 @@ struct Args {
     #[arg(short = 'a', long, env = "ALERTMANAGER_URL", help = "Alertmanager URL")]
     alertmanager_url: String,
 
-    #[arg(short = 't', long, env = "SLACK_BOT_TOKEN", help = "Slack bot token")]
-    slack_bot_token: String,
-
-    #[arg(short = 'c', long, env = "SLACK_CHANNEL_ID", help = "Slack channel ID")]
-    slack_channel: String,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-struct Silence {
-    id: String,
-    status: SilenceStatus,
-    matchers: Vec<Matcher>,
-    #[serde(rename = "startsAt")]
-    starts_at: String,
-    #[serde(rename = "endsAt")]
-    ends_at: String,
-    #[serde(rename = "updatedAt")]
-    updated_at: String,
-    #[serde(rename = "createdBy")]
-    created_by: String,
-    comment: String,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-struct SilenceStatus {
-    state: String,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-struct Matcher {
-    name: String,
-    value: String,
-    #[serde(rename = "isRegex")]
-    is_regex: bool,
-    #[serde(rename = "isEqual")]
-    is_equal: bool,
-}
-
-#[derive(Debug, Serialize)]
-struct SlackMessage {
-    blocks: Vec<SlackBlock>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-#[serde(tag = "type")]
-enum SlackBlock {
-    #[serde(rename = "header")]
-    Header { text: SlackText },
-    #[serde(rename = "section")]
-    Section { text: SlackText },
-    #[serde(rename = "divider")]
-    Divider {},
+    #[arg(
+        short = 'o',
+        long,
+        env = "OUTPUT",
+        value_enum,
+        default_value = "slack",
+        help = "Where to deliver the report"
+    )]
+    output: OutputKind,
+
+    #[arg(long, env = "SLACK_BOT_TOKEN", help = "Slack bot token")]
+    slack_bot_token: Option<String>,
+
+    #[arg(long, env = "SLACK_CHANNEL_ID", help = "Slack channel ID")]
+    slack_channel: Option<String>,
+
+    #[arg(long, env = "WEBHOOK_URL", help = "Webhook URL to POST silences to as JSON")]
+    webhook_url: Option<String>,
+
+    #[arg(long, env = "TELEGRAM_BOT_TOKEN", help = "Telegram bot token")]
+    telegram_bot_token: Option<String>,
+
+    #[arg(long, env = "TELEGRAM_CHAT_ID", help = "Telegram chat ID")]
+    telegram_chat_id: Option<String>,
+
+    #[arg(
+        long,
+        env = "SILENCE_STATES",
+        value_delimiter = ',',
+        help = "Only report silences in these states (e.g. active,pending)"
+    )]
+    state: Vec<String>,
+
+    #[arg(
+        long = "matcher",
+        env = "SILENCE_MATCHERS",
+        value_delimiter = ',',
+        help = "Only report silences with a matcher name=value (repeatable)"
+    )]
+    matchers: Vec<String>,
+
+    #[arg(
+        long,
+        env = "INTERVAL",
+        value_parser = parse_interval,
+        help = "Re-run on this interval (e.g. 30s, 5m, 1h) instead of exiting after one report"
+    )]
+    interval: Option<Duration>,
+
+    #[arg(
+        long,
+        env = "STATE_FILE",
+        default_value = ".silences-reporter-state.json",
+        help = "Path to the fingerprint store used for change-only reporting in daemon mode"
+    )]
+    state_file: PathBuf,
 }
 
-#[derive(Debug, Clone, Serialize)]
-struct SlackText {
-    #[serde(rename = "type")]
-    text_type: String,
-    text: String,
-}
+/// Parses a duration like `30s`, `5m`, `1h` or `2d` into a `Duration`.
+fn parse_interval(raw: &str) -> Result<Duration, String> {
+    let raw = raw.trim();
+
+    let last_char = raw
+        .chars()
+        .last()
+        .ok_or_else(|| "duration must not be empty".to_string())?;
+    let (value, unit) = raw.split_at(raw.len() - last_char.len_utf8());
+
+    let value: u64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration: '{}' (expected e.g. 30s, 5m, 1h, 2d)", raw))?;
+
+    let secs = match unit {
+        "s" => value,
+        "m" => value * 60,
+        "h" => value * 3600,
+        "d" => value * 86400,
+        _ => return Err(format!("unknown duration unit in '{}' (use s/m/h/d)", raw)),
+    };
 
-#[derive(Debug, Serialize)]
-struct SlackApiMessage {
-    channel: String,
-    blocks: Vec<SlackBlock>,
+    Ok(Duration::from_secs(secs))
 }
 
-#[derive(Debug, Deserialize)]
-struct SlackApiResponse {
-    ok: bool,
-    error: Option<String>,
+fn build_sink(args: &Args) -> Result<Box<dyn ReportSink>> {
+    match args.output {
+        OutputKind::Slack => Ok(Box::new(SlackSink {
+            token: args
+                .slack_bot_token
+                .clone()
+                .context("--slack-bot-token is required when --output=slack")?,
+            channel: args
+                .slack_channel
+                .clone()
+                .context("--slack-channel is required when --output=slack")?,
+        })),
+        OutputKind::Webhook => Ok(Box::new(WebhookSink {
+            url: args
+                .webhook_url
+                .clone()
+                .context("--webhook-url is required when --output=webhook")?,
+        })),
+        OutputKind::Telegram => Ok(Box::new(TelegramSink {
+            bot_token: args
+                .telegram_bot_token
+                .clone()
+                .context("--telegram-bot-token is required when --output=telegram")?,
+            chat_id: args
+                .telegram_chat_id
+                .clone()
+                .context("--telegram-chat-id is required when --output=telegram")?,
+        })),
+    }
 }
 
 fn fetch_silences(alertmanager_url: &str) -> Result<Vec<Silence>> {
@@ -102,260 +150,181 @@ fn fetch_silences(alertmanager_url: &str) -> Result<Vec<Silence>> {
     Ok(silences)
 }
 
-fn format_slack_messages(silences: &[Silence]) -> Vec<SlackMessage> {
-    // Slack has a 50 block limit per message
-    // Header (1) + Summary Section (1) + Divider (1) = 3 blocks used
-    // Each silence uses 2 blocks (Section + Divider)
-    // We can safely show up to 23 silences per message: (50 - 3) / 2 = 23
-    const MAX_SILENCES_PER_MESSAGE: usize = 23;
-    
-    let mut messages = Vec::new();
-    
-    let mut active_count = 0;
-    let mut expired_count = 0;
-    let mut pending_count = 0;
-
-    for silence in silences {
-        match silence.status.state.as_str() {
-            "active" => active_count += 1,
-            "expired" => expired_count += 1,
-            "pending" => pending_count += 1,
-            _ => {}
-        }
-    }
-
-    // Split silences into chunks (or create one empty chunk if no silences)
-    let chunks: Vec<&[Silence]> = if silences.is_empty() {
-        vec![&[]]
-    } else {
-        silences.chunks(MAX_SILENCES_PER_MESSAGE).collect()
-    };
-    let total_parts = chunks.len();
-
-    for (part_num, chunk) in chunks.iter().enumerate() {
-        let mut blocks = vec![];
-        
-        // Header with part number if multiple parts
-        let header_text = if total_parts > 1 {
-            format!("Alertmanager Silences Report (Part {}/{})", part_num + 1, total_parts)
-        } else {
-            "Alertmanager Silences Report".to_string()
-        };
-        
-        blocks.push(SlackBlock::Header {
-            text: SlackText {
-                text_type: "plain_text".to_string(),
-                text: header_text,
-            },
-        });
-
-        // Add summary only to first message
-        if part_num == 0 {
-            let summary = format!(
-                "*Total:* {} | *Active:* {} | *Pending:* {} | *Expired:* {}",
-                silences.len(),
-                active_count,
-                pending_count,
-                expired_count
-            );
-
-            blocks.push(SlackBlock::Section {
-                text: SlackText {
-                    text_type: "mrkdwn".to_string(),
-                    text: summary,
-                },
-            });
-        }
-
-        blocks.push(SlackBlock::Divider {});
-
-        // Add silences for this chunk
-        for silence in *chunk {
-            let matchers_list = silence
-                .matchers
-                .iter()
-                .map(|m| {
-                    let operator = if m.is_equal { "=" } else { "!=" };
-                    let regex_marker = if m.is_regex { "~" } else { "" };
-                    format!("  • `{}{}{}{}`", m.name, operator, regex_marker, m.value)
-                })
-                .collect::<Vec<_>>()
-                .join("\n");
-
-            let mut text = format!(
-                "*Status:* {}\n*Date:* {} → {}\n*CreatedBy:* {}\n*Matchers:*\n{}",
-                silence.status.state,
-                format_timestamp(&silence.starts_at),
-                format_timestamp(&silence.ends_at),
-                silence.created_by,
-                matchers_list
-            );
-
-            if !silence.comment.is_empty() && silence.comment != "-" && silence.comment != "." {
-                let comment_preview = if silence.comment.len() > 100 {
-                    format!("{}...", &silence.comment[..100])
-                } else {
-                    silence.comment.clone()
-                };
-                text.push_str(&format!("\n*Comment:* _{}_", comment_preview));
-            }
-
-            blocks.push(SlackBlock::Section {
-                text: SlackText {
-                    text_type: "mrkdwn".to_string(),
-                    text,
-                },
-            });
-
-            blocks.push(SlackBlock::Divider {});
-        }
-
-        messages.push(SlackMessage { blocks });
-    }
+/// Parses `--matcher name=value` entries into `(name, value)` pairs.
+///
+/// Entries without a `=` are ignored since they can't match anything.
+fn parse_matcher_filters(raw: &[String]) -> Vec<(String, String)> {
+    raw.iter()
+        .filter_map(|entry| entry.split_once('='))
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
 
-    messages
+/// Keeps only silences whose state is in `states` (when non-empty) and
+/// whose matchers contain every `name=value` pair in `matchers`.
+fn filter_silences(silences: Vec<Silence>, states: &[String], matchers: &[(String, String)]) -> Vec<Silence> {
+    silences
+        .into_iter()
+        .filter(|silence| states.is_empty() || states.iter().any(|state| state == &silence.status.state))
+        .filter(|silence| {
+            matchers.iter().all(|(name, value)| {
+                silence
+                    .matchers
+                    .iter()
+                    .any(|m| &m.name == name && &m.value == value)
+            })
+        })
+        .collect()
 }
 
-fn format_timestamp(timestamp: &str) -> String {
-    timestamp
-        .replace("T", " ")
-        .replace("Z", "")
-        .split('.')
-        .next()
-        .unwrap_or(timestamp)
-        .to_string()
+/// Fetches silences from Alertmanager and applies the `--state`/`--matcher`
+/// filters, producing the set that will actually be reported.
+fn fetch_and_filter(args: &Args) -> Result<Vec<Silence>> {
+    let silences = fetch_silences(&args.alertmanager_url)?;
+    let matcher_filters = parse_matcher_filters(&args.matchers);
+    Ok(filter_silences(silences, &args.state, &matcher_filters))
 }
 
-fn send_to_slack(token: &str, channel: &str, message: &SlackMessage) -> Result<()> {
-    let client = reqwest::blocking::Client::new();
+fn run_once(args: &Args, sink: &dyn ReportSink) -> Result<()> {
+    let silences = fetch_and_filter(args)?;
+    println!("Found {} silence(s)", silences.len());
 
-    let api_message = SlackApiMessage {
-        channel: channel.to_string(),
-        blocks: message.blocks.clone(),
-    };
+    sink.send(&silences, None)
+}
 
-    let response = client
-        .post("https://slack.com/api/chat.postMessage")
-        .header("Authorization", format!("Bearer {}", token))
-        .header("Content-Type", "application/json")
-        .json(&api_message)
-        .send()
-        .context("Failed to send message to Slack API")?;
+/// Re-fetches silences on `interval` and only reports when the set has
+/// changed since the previous tick, persisting a fingerprint of the last
+/// reported set to `args.state_file` across ticks.
+fn run_daemon(args: &Args, sink: &dyn ReportSink, interval: Duration) -> Result<()> {
+    println!("Starting daemon mode, checking every {:?}", interval);
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().unwrap_or_default();
-        anyhow::bail!("Slack API returned error status {}: {}", status, body);
+    loop {
+        if let Err(err) = run_daemon_tick(args, sink) {
+            eprintln!("Daemon tick failed: {:#}", err);
+        }
+
+        std::thread::sleep(interval);
     }
+}
 
-    let slack_response: SlackApiResponse = response
-        .json()
-        .context("Failed to parse Slack API response")?;
-
-    if !slack_response.ok {
-        anyhow::bail!(
-            "Slack API returned error: {}",
-            slack_response
-                .error
-                .unwrap_or_else(|| "unknown error".to_string())
+fn run_daemon_tick(args: &Args, sink: &dyn ReportSink) -> Result<()> {
+    let silences = fetch_and_filter(args)?;
+    let current_fingerprints = state::fingerprint_silences(&silences);
+    let previous_fingerprints = state::load_fingerprints(&args.state_file)?;
+    let diff = state::diff_fingerprints(&previous_fingerprints, &current_fingerprints);
+
+    if !diff.has_changes() {
+        println!(
+            "No changes since last run ({} silence(s)), skipping report",
+            silences.len()
         );
+        return Ok(());
     }
 
+    println!(
+        "Changes detected (+{} -{} ~{}), reporting {} silence(s)",
+        diff.added,
+        diff.removed,
+        diff.changed,
+        silences.len()
+    );
+
+    sink.send(&silences, Some(&diff))?;
+    state::save_fingerprints(&args.state_file, &current_fingerprints)?;
+
     Ok(())
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
+    let sink = build_sink(&args)?;
 
     println!(
         "Fetching silences from Alertmanager: {}",
         args.alertmanager_url
     );
 
-    let silences = fetch_silences(&args.alertmanager_url)?;
-
-    println!("Found {} silence(s)", silences.len());
-
-    let messages = format_slack_messages(&silences);
-
-    println!("Sending {} message(s) to Slack", messages.len());
-
-    for (i, message) in messages.iter().enumerate() {
-        send_to_slack(&args.slack_bot_token, &args.slack_channel, message)?;
-        println!("Message {}/{} sent successfully", i + 1, messages.len());
-        
-        // Small delay between messages to avoid rate limiting
-        if i < messages.len() - 1 {
-            std::thread::sleep(std::time::Duration::from_millis(100));
-        }
+    match args.interval {
+        Some(interval) => run_daemon(&args, sink.as_ref(), interval),
+        None => run_once(&args, sink.as_ref()),
     }
-
-    println!("All reports sent to Slack successfully");
-
-    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use model::{Matcher, SilenceStatus};
 
-    #[test]
-    fn test_format_slack_messages_empty() {
-        let silences = vec![];
-        let messages = format_slack_messages(&silences);
-        assert_eq!(messages.len(), 1);
-        assert!(messages[0].blocks.len() >= 3);
-    }
-
-    #[test]
-    fn test_format_slack_messages_with_data() {
-        let silences = vec![Silence {
-            id: "test-id-123".to_string(),
+    fn make_silence(id: &str, state: &str, matchers: Vec<Matcher>) -> Silence {
+        Silence {
+            id: id.to_string(),
             status: SilenceStatus {
-                state: "active".to_string(),
+                state: state.to_string(),
             },
-            matchers: vec![Matcher {
-                name: "alertname".to_string(),
-                value: "TestAlert".to_string(),
-                is_regex: false,
-                is_equal: true,
-            }],
+            matchers,
             starts_at: "2024-01-01T00:00:00Z".to_string(),
             ends_at: "2024-01-02T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             created_by: "test-user".to_string(),
             comment: "Test comment".to_string(),
-        }];
+        }
+    }
 
-        let messages = format_slack_messages(&silences);
-        assert_eq!(messages.len(), 1);
-        assert!(messages[0].blocks.len() > 3);
+    #[test]
+    fn test_filter_silences_by_state() {
+        let silences = vec![
+            make_silence("1", "active", vec![]),
+            make_silence("2", "expired", vec![]),
+        ];
+
+        let filtered = filter_silences(silences, &["active".to_string()], &[]);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "1");
     }
 
     #[test]
-    fn test_format_slack_messages_multiple_parts() {
-        // Create 50 silences to test message splitting
-        let silences: Vec<Silence> = (0..50)
-            .map(|i| Silence {
-                id: format!("test-id-{}", i),
-                status: SilenceStatus {
-                    state: "active".to_string(),
-                },
-                matchers: vec![Matcher {
-                    name: "alertname".to_string(),
-                    value: format!("TestAlert{}", i),
+    fn test_filter_silences_by_matcher() {
+        let silences = vec![
+            make_silence(
+                "1",
+                "active",
+                vec![Matcher {
+                    name: "team".to_string(),
+                    value: "payments".to_string(),
                     is_regex: false,
                     is_equal: true,
                 }],
-                starts_at: "2024-01-01T00:00:00Z".to_string(),
-                ends_at: "2024-01-02T00:00:00Z".to_string(),
-                updated_at: "2024-01-01T00:00:00Z".to_string(),
-                created_by: "test-user".to_string(),
-                comment: "Test comment".to_string(),
-            })
-            .collect();
+            ),
+            make_silence(
+                "2",
+                "active",
+                vec![Matcher {
+                    name: "team".to_string(),
+                    value: "infra".to_string(),
+                    is_regex: false,
+                    is_equal: true,
+                }],
+            ),
+        ];
+
+        let filters = parse_matcher_filters(&["team=payments".to_string()]);
+        let filtered = filter_silences(silences, &[], &filters);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "1");
+    }
 
-        let messages = format_slack_messages(&silences);
-        assert_eq!(messages.len(), 3); // 50 silences should create 3 messages (23 + 23 + 4)
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_interval("5m").unwrap(), Duration::from_secs(5 * 60));
+        assert_eq!(parse_interval("1h").unwrap(), Duration::from_secs(3600));
+        assert_eq!(parse_interval("2d").unwrap(), Duration::from_secs(2 * 86400));
+        assert!(parse_interval("5x").is_err());
+    }
+
+    #[test]
+    fn test_parse_interval_rejects_multi_byte_unit_without_panicking() {
+        assert!(parse_interval("5δ").is_err());
+        assert!(parse_interval("").is_err());
     }
 }
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Silence {
+    pub id: String,
+    pub status: SilenceStatus,
+    pub matchers: Vec<Matcher>,
+    #[serde(rename = "startsAt")]
+    pub starts_at: String,
+    #[serde(rename = "endsAt")]
+    pub ends_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+    #[serde(rename = "createdBy")]
+    pub created_by: String,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SilenceStatus {
+    pub state: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Matcher {
+    pub name: String,
+    pub value: String,
+    #[serde(rename = "isRegex")]
+    pub is_regex: bool,
+    #[serde(rename = "isEqual")]
+    pub is_equal: bool,
+}
+
+/// Added/removed/changed counts between two fetches of the silence set,
+/// used by daemon mode to report what changed since the previous run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SilenceDiff {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+}
+
+impl SilenceDiff {
+    pub fn has_changes(&self) -> bool {
+        self.added > 0 || self.removed > 0 || self.changed > 0
+    }
+}
+
+pub fn format_timestamp(timestamp: &str) -> String {
+    timestamp
+        .replace("T", " ")
+        .replace("Z", "")
+        .split('.')
+        .next()
+        .unwrap_or(timestamp)
+        .to_string()
+}
@@ -0,0 +1,239 @@
+mod slack;
+mod telegram;
+mod webhook;
+
+pub use slack::SlackSink;
+pub use telegram::TelegramSink;
+pub use webhook::WebhookSink;
+
+use crate::model::{Silence, SilenceDiff};
+use anyhow::Result;
+use clap::ValueEnum;
+
+/// Where a report can be delivered. `format_slack_messages` stays
+/// Slack-specific; Telegram uses `render_report` below, and the webhook
+/// sink posts the silences as raw JSON.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum OutputKind {
+    Slack,
+    Webhook,
+    Telegram,
+}
+
+/// A destination that a silences report can be delivered to.
+///
+/// `diff` is `Some` in daemon mode, when the sink should call out what
+/// changed since the previous tick; it is `None` for one-shot runs.
+pub trait ReportSink {
+    fn send(&self, silences: &[Silence], diff: Option<&SilenceDiff>) -> Result<()>;
+}
+
+/// Escapes the characters Telegram's MarkdownV2 treats as special
+/// (https://core.telegram.org/bots/api#markdownv2-style). `render_report`
+/// interpolates Alertmanager-controlled text (matcher names/values,
+/// `created_by`, comments) into a MarkdownV2 message, and Telegram rejects
+/// the whole `sendMessage` call with a 400 if any of these appear
+/// unescaped — so every dynamic field must be passed through this first.
+fn escape_markdown_v2(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '_' | '*' | '[' | ']' | '(' | ')' | '~' | '`' | '>' | '#' | '+' | '-' | '=' | '|'
+                | '{' | '}' | '.' | '!' | '\\'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Max chars of a silence comment shown before truncating with `...`,
+/// matching `COMMENT_PREVIEW_CHARS` in `slack.rs`.
+const COMMENT_PREVIEW_CHARS: usize = 100;
+
+/// Renders the MarkdownV2 summary sent by the Telegram sink. All
+/// Alertmanager-controlled text is passed through `escape_markdown_v2`;
+/// the literal MarkdownV2 special characters in the template itself
+/// (`|`, `-`, `>`, `(`, `)`, ...) are pre-escaped below.
+fn render_report(silences: &[Silence], diff: Option<&SilenceDiff>) -> String {
+    let mut active_count = 0;
+    let mut expired_count = 0;
+    let mut pending_count = 0;
+
+    for silence in silences {
+        match silence.status.state.as_str() {
+            "active" => active_count += 1,
+            "expired" => expired_count += 1,
+            "pending" => pending_count += 1,
+            _ => {}
+        }
+    }
+
+    let mut report = format!(
+        "*Alertmanager Silences Report*\nTotal: {} \\| Active: {} \\| Pending: {} \\| Expired: {}",
+        silences.len(),
+        active_count,
+        pending_count,
+        expired_count
+    );
+
+    if let Some(diff) = diff {
+        report.push_str(&format!(
+            "\nSince last run: \\+{} \\-{} \\~{}",
+            diff.added, diff.removed, diff.changed
+        ));
+    }
+
+    report.push('\n');
+
+    for silence in silences {
+        let matchers_list = silence
+            .matchers
+            .iter()
+            .map(|m| {
+                let operator = if m.is_equal { "\\=" } else { "\\!\\=" };
+                let regex_marker = if m.is_regex { "\\~" } else { "" };
+                format!(
+                    "{}{}{}{}",
+                    escape_markdown_v2(&m.name),
+                    operator,
+                    regex_marker,
+                    escape_markdown_v2(&m.value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        report.push_str(&format!(
+            "\n\\- \\[{}\\] {} \\-\\> {} by {} \\({}\\)",
+            escape_markdown_v2(&silence.status.state),
+            escape_markdown_v2(&crate::model::format_timestamp(&silence.starts_at)),
+            escape_markdown_v2(&crate::model::format_timestamp(&silence.ends_at)),
+            escape_markdown_v2(&silence.created_by),
+            matchers_list
+        ));
+
+        if !silence.comment.is_empty() && silence.comment != "-" && silence.comment != "." {
+            let comment_preview = if silence.comment.chars().count() > COMMENT_PREVIEW_CHARS {
+                let truncated: String =
+                    silence.comment.chars().take(COMMENT_PREVIEW_CHARS).collect();
+                format!("{}\\.\\.\\.", escape_markdown_v2(&truncated))
+            } else {
+                escape_markdown_v2(&silence.comment)
+            };
+            report.push_str(&format!("\n  {}", comment_preview));
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Matcher, SilenceStatus};
+
+    #[test]
+    fn test_render_report_empty() {
+        let report = render_report(&[], None);
+        assert_eq!(
+            report,
+            "*Alertmanager Silences Report*\nTotal: 0 \\| Active: 0 \\| Pending: 0 \\| Expired: 0\n"
+        );
+    }
+
+    #[test]
+    fn test_render_report_counts_by_state() {
+        let silences = vec![
+            silence_with_state("active"),
+            silence_with_state("active"),
+            silence_with_state("pending"),
+            silence_with_state("expired"),
+        ];
+
+        let report = render_report(&silences, None);
+        assert!(report.contains("Total: 4 \\| Active: 2 \\| Pending: 1 \\| Expired: 1"));
+    }
+
+    #[test]
+    fn test_render_report_includes_diff_when_present() {
+        let diff = SilenceDiff {
+            added: 2,
+            removed: 1,
+            changed: 3,
+        };
+
+        let report = render_report(&[], Some(&diff));
+        assert!(report.contains("Since last run: \\+2 \\-1 \\~3"));
+    }
+
+    #[test]
+    fn test_render_report_omits_diff_when_absent() {
+        let report = render_report(&[], None);
+        assert!(!report.contains("Since last run"));
+    }
+
+    #[test]
+    fn test_render_report_escapes_markdown_v2_special_chars_in_dynamic_fields() {
+        let mut silence = silence_with_state("active");
+        silence.created_by = "blocked_until PR*123 merges".to_string();
+        silence.matchers = vec![Matcher {
+            name: "severity".to_string(),
+            value: "critical!".to_string(),
+            is_regex: false,
+            is_equal: true,
+        }];
+
+        let report = render_report(&[silence], None);
+        assert!(report.contains("blocked\\_until PR\\*123 merges"));
+        assert!(report.contains("severity\\=critical\\!"));
+        assert!(!report.contains("PR*123"));
+    }
+
+    #[test]
+    fn test_render_report_includes_comment_when_present() {
+        let mut silence = silence_with_state("active");
+        silence.comment = "root cause: flaky disk, ticket INFRA-123".to_string();
+
+        let report = render_report(&[silence], None);
+        assert!(report.contains("root cause: flaky disk, ticket INFRA\\-123"));
+    }
+
+    #[test]
+    fn test_render_report_omits_placeholder_comments() {
+        for placeholder in ["", "-", "."] {
+            let mut silence = silence_with_state("active");
+            silence.comment = placeholder.to_string();
+
+            let report = render_report(&[silence], None);
+            assert_eq!(report.matches('\n').count(), 3);
+        }
+    }
+
+    #[test]
+    fn test_render_report_truncates_long_comment() {
+        let mut silence = silence_with_state("active");
+        silence.comment = "a".repeat(COMMENT_PREVIEW_CHARS + 20);
+
+        let report = render_report(&[silence], None);
+        assert!(report.contains(&format!("{}\\.\\.\\.", "a".repeat(COMMENT_PREVIEW_CHARS))));
+    }
+
+    fn silence_with_state(state: &str) -> Silence {
+        Silence {
+            id: "test-id".to_string(),
+            status: SilenceStatus {
+                state: state.to_string(),
+            },
+            matchers: vec![],
+            starts_at: "2024-01-01T00:00:00Z".to_string(),
+            ends_at: "2024-01-02T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            created_by: "test-user".to_string(),
+            comment: "".to_string(),
+        }
+    }
+}
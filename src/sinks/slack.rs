@@ -0,0 +1,566 @@
+use super::ReportSink;
+use crate::model::{format_timestamp, Silence, SilenceDiff};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+struct SlackMessage {
+    blocks: Vec<SlackBlock>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+enum SlackBlock {
+    #[serde(rename = "header")]
+    Header { text: SlackText },
+    #[serde(rename = "section")]
+    Section { text: SlackText },
+    #[serde(rename = "divider")]
+    Divider {},
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SlackText {
+    #[serde(rename = "type")]
+    text_type: String,
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SlackApiMessage {
+    channel: String,
+    blocks: Vec<SlackBlock>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread_ts: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackApiResponse {
+    ok: bool,
+    error: Option<String>,
+    ts: Option<String>,
+}
+
+/// Maximum number of attempts for a single `chat.postMessage` call before
+/// giving up on a rate-limited request.
+const MAX_TRIES: u32 = 5;
+
+/// Fallback backoff when Slack returns a 429 without a `Retry-After` header.
+const DEFAULT_RETRY_AFTER_SECS: u64 = 1;
+
+/// Delivers a silences report to a Slack channel via `chat.postMessage`,
+/// splitting it into multiple messages and threading parts 2..N under
+/// part 1 when it doesn't fit in a single message.
+pub struct SlackSink {
+    pub token: String,
+    pub channel: String,
+}
+
+impl ReportSink for SlackSink {
+    fn send(&self, silences: &[Silence], diff: Option<&SilenceDiff>) -> Result<()> {
+        let messages = format_slack_messages(silences, diff);
+
+        println!("Sending {} message(s) to Slack", messages.len());
+
+        let mut thread_ts: Option<String> = None;
+
+        for (i, message) in messages.iter().enumerate() {
+            let ts = send_to_slack(&self.token, &self.channel, message, thread_ts.clone())?;
+            println!("Message {}/{} sent successfully", i + 1, messages.len());
+
+            if thread_ts.is_none() {
+                thread_ts = ts;
+            }
+
+            // Small delay between messages to avoid rate limiting
+            if i < messages.len() - 1 {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+        }
+
+        println!("All reports sent to Slack successfully");
+
+        Ok(())
+    }
+}
+
+/// Slack's hard limit on blocks per message.
+const MAX_BLOCKS_PER_MESSAGE: usize = 50;
+
+/// Blocks used by the header + summary section + divider on the first
+/// message (the summary is only shown once).
+const FIRST_MESSAGE_OVERHEAD: usize = 3;
+
+/// Blocks used by the header + leading divider on every later message.
+const OTHER_MESSAGE_OVERHEAD: usize = 2;
+
+/// Slack's hard limit on characters per `text` object in a block.
+const MAX_SECTION_TEXT_CHARS: usize = 3000;
+
+/// Max chars of a silence comment shown before truncating with `...`.
+const COMMENT_PREVIEW_CHARS: usize = 100;
+
+/// An iterator that walks a `&str` and yields slices of at most `max_chars`
+/// chars each, never splitting inside a multi-byte UTF-8 boundary.
+struct StrChunks<'a> {
+    remaining: &'a str,
+    max_chars: usize,
+}
+
+impl<'a> StrChunks<'a> {
+    fn new(s: &'a str, max_chars: usize) -> Self {
+        Self {
+            remaining: s,
+            max_chars,
+        }
+    }
+}
+
+impl<'a> Iterator for StrChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let split_at = self
+            .remaining
+            .char_indices()
+            .nth(self.max_chars)
+            .map(|(byte_idx, _)| byte_idx)
+            .unwrap_or(self.remaining.len());
+
+        let (chunk, rest) = self.remaining.split_at(split_at);
+        self.remaining = rest;
+        Some(chunk)
+    }
+}
+
+/// Builds the Section (+ overflow Sections) and trailing Divider for a
+/// single silence, splitting `text` across multiple Section blocks when it
+/// exceeds Slack's per-block character limit.
+fn build_silence_blocks(silence: &Silence) -> Vec<SlackBlock> {
+    let matchers_list = silence
+        .matchers
+        .iter()
+        .map(|m| {
+            let operator = if m.is_equal { "=" } else { "!=" };
+            let regex_marker = if m.is_regex { "~" } else { "" };
+            format!("  • `{}{}{}{}`", m.name, operator, regex_marker, m.value)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut text = format!(
+        "*Status:* {}\n*Date:* {} → {}\n*CreatedBy:* {}\n*Matchers:*\n{}",
+        silence.status.state,
+        format_timestamp(&silence.starts_at),
+        format_timestamp(&silence.ends_at),
+        silence.created_by,
+        matchers_list
+    );
+
+    if !silence.comment.is_empty() && silence.comment != "-" && silence.comment != "." {
+        let comment_preview = if silence.comment.chars().count() > COMMENT_PREVIEW_CHARS {
+            let truncated = StrChunks::new(&silence.comment, COMMENT_PREVIEW_CHARS)
+                .next()
+                .unwrap_or("");
+            format!("{}...", truncated)
+        } else {
+            silence.comment.clone()
+        };
+        text.push_str(&format!("\n*Comment:* _{}_", comment_preview));
+    }
+
+    let mut blocks: Vec<SlackBlock> = StrChunks::new(&text, MAX_SECTION_TEXT_CHARS)
+        .map(|chunk| SlackBlock::Section {
+            text: SlackText {
+                text_type: "mrkdwn".to_string(),
+                text: chunk.to_string(),
+            },
+        })
+        .collect();
+
+    blocks.push(SlackBlock::Divider {});
+
+    blocks
+}
+
+/// Greedily packs blocks into as few messages as possible without
+/// exceeding `MAX_BLOCKS_PER_MESSAGE`, accounting for the header (and
+/// summary, on the first message) overhead. Packing operates block-by-block
+/// rather than silence-by-silence, so a single silence whose own blocks
+/// (e.g. from many chunked sections) overflow a message's budget is split
+/// across messages instead of blowing through the block ceiling.
+fn pack_blocks(blocks: Vec<SlackBlock>) -> Vec<Vec<SlackBlock>> {
+    if blocks.is_empty() {
+        return vec![vec![]];
+    }
+
+    let mut messages = Vec::new();
+    let mut current = Vec::new();
+
+    for block in blocks {
+        let overhead = if messages.is_empty() {
+            FIRST_MESSAGE_OVERHEAD
+        } else {
+            OTHER_MESSAGE_OVERHEAD
+        };
+        let budget = MAX_BLOCKS_PER_MESSAGE - overhead;
+
+        if current.len() >= budget {
+            messages.push(std::mem::take(&mut current));
+        }
+
+        current.push(block);
+    }
+
+    messages.push(current);
+
+    messages
+}
+
+fn format_slack_messages(silences: &[Silence], diff: Option<&SilenceDiff>) -> Vec<SlackMessage> {
+    let mut active_count = 0;
+    let mut expired_count = 0;
+    let mut pending_count = 0;
+
+    for silence in silences {
+        match silence.status.state.as_str() {
+            "active" => active_count += 1,
+            "expired" => expired_count += 1,
+            "pending" => pending_count += 1,
+            _ => {}
+        }
+    }
+
+    let flat_blocks: Vec<SlackBlock> = silences.iter().flat_map(build_silence_blocks).collect();
+    let packed = pack_blocks(flat_blocks);
+    let total_parts = packed.len();
+
+    packed
+        .into_iter()
+        .enumerate()
+        .map(|(part_num, part_blocks)| {
+            let mut blocks = vec![];
+
+            // Header with part number if multiple parts
+            let header_text = if total_parts > 1 {
+                format!("Alertmanager Silences Report (Part {}/{})", part_num + 1, total_parts)
+            } else {
+                "Alertmanager Silences Report".to_string()
+            };
+
+            blocks.push(SlackBlock::Header {
+                text: SlackText {
+                    text_type: "plain_text".to_string(),
+                    text: header_text,
+                },
+            });
+
+            // Add summary only to first message
+            if part_num == 0 {
+                let mut summary = format!(
+                    "*Total:* {} | *Active:* {} | *Pending:* {} | *Expired:* {}",
+                    silences.len(),
+                    active_count,
+                    pending_count,
+                    expired_count
+                );
+
+                if let Some(diff) = diff {
+                    summary.push_str(&format!(
+                        " | *Added:* {} | *Removed:* {} | *Changed:* {}",
+                        diff.added, diff.removed, diff.changed
+                    ));
+                }
+
+                blocks.push(SlackBlock::Section {
+                    text: SlackText {
+                        text_type: "mrkdwn".to_string(),
+                        text: summary,
+                    },
+                });
+            }
+
+            blocks.push(SlackBlock::Divider {});
+
+            blocks.extend(part_blocks);
+
+            SlackMessage { blocks }
+        })
+        .collect()
+}
+
+/// Posts `message` to Slack, returning the `ts` of the posted message so
+/// callers can thread subsequent parts beneath it via `thread_ts`.
+fn send_to_slack(
+    token: &str,
+    channel: &str,
+    message: &SlackMessage,
+    thread_ts: Option<String>,
+) -> Result<Option<String>> {
+    let client = reqwest::blocking::Client::new();
+
+    let api_message = SlackApiMessage {
+        channel: channel.to_string(),
+        blocks: message.blocks.clone(),
+        thread_ts,
+    };
+
+    retry_slack(&client, token, &api_message)
+}
+
+/// Posts `api_message` to `chat.postMessage`, retrying on rate limits.
+///
+/// Slack signals throttling either as an HTTP 429 with a `Retry-After`
+/// header, or as a 200 response whose body has `ok: false` and
+/// `error: "ratelimited"`. Both are treated the same: sleep for the
+/// indicated number of seconds (or `DEFAULT_RETRY_AFTER_SECS` when no
+/// header is present) and retry, up to `MAX_TRIES` attempts. Any other
+/// error fails immediately. On success, returns the response `ts`.
+fn retry_slack(
+    client: &reqwest::blocking::Client,
+    token: &str,
+    api_message: &SlackApiMessage,
+) -> Result<Option<String>> {
+    for attempt in 1..=MAX_TRIES {
+        let response = client
+            .post("https://slack.com/api/chat.postMessage")
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .json(api_message)
+            .send()
+            .context("Failed to send message to Slack API")?;
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = retry_after_secs(response.headers());
+
+            if attempt == MAX_TRIES {
+                anyhow::bail!(
+                    "Slack API rate-limited after {} attempts, giving up",
+                    MAX_TRIES
+                );
+            }
+
+            println!(
+                "Rate-limited by Slack, retrying in {}s (attempt {}/{})",
+                retry_after, attempt, MAX_TRIES
+            );
+            std::thread::sleep(std::time::Duration::from_secs(retry_after));
+            continue;
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!("Slack API returned error status {}: {}", status, body);
+        }
+
+        let retry_after = retry_after_secs(response.headers());
+        let slack_response: SlackApiResponse = response
+            .json()
+            .context("Failed to parse Slack API response")?;
+
+        if !slack_response.ok {
+            if slack_response.error.as_deref() == Some("ratelimited") {
+                if attempt == MAX_TRIES {
+                    anyhow::bail!(
+                        "Slack API rate-limited after {} attempts, giving up",
+                        MAX_TRIES
+                    );
+                }
+
+                println!(
+                    "Rate-limited by Slack, retrying in {}s (attempt {}/{})",
+                    retry_after, attempt, MAX_TRIES
+                );
+                std::thread::sleep(std::time::Duration::from_secs(retry_after));
+                continue;
+            }
+
+            anyhow::bail!(
+                "Slack API returned error: {}",
+                slack_response
+                    .error
+                    .unwrap_or_else(|| "unknown error".to_string())
+            );
+        }
+
+        return Ok(slack_response.ts);
+    }
+
+    anyhow::bail!(
+        "Slack API rate-limited after {} attempts, giving up",
+        MAX_TRIES
+    );
+}
+
+/// Reads the `Retry-After` header (in seconds), falling back to
+/// `DEFAULT_RETRY_AFTER_SECS` when it is absent or not a valid integer.
+fn retry_after_secs(headers: &reqwest::header::HeaderMap) -> u64 {
+    headers
+        .get("Retry-After")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_RETRY_AFTER_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Matcher, SilenceStatus};
+
+    #[test]
+    fn test_str_chunks_splits_without_breaking_utf8_boundaries() {
+        let text = "a".repeat(5) + "日本語" + &"b".repeat(5);
+        let chunks: Vec<&str> = StrChunks::new(&text, 6).collect();
+        assert_eq!(chunks.join(""), text);
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= 6);
+        }
+    }
+
+    #[test]
+    fn test_format_slack_messages_splits_oversized_silence_across_sections() {
+        let silence = Silence {
+            id: "test-id-big".to_string(),
+            status: SilenceStatus {
+                state: "active".to_string(),
+            },
+            matchers: (0..200)
+                .map(|i| Matcher {
+                    name: format!("label{}", i),
+                    value: "x".repeat(20),
+                    is_regex: false,
+                    is_equal: true,
+                })
+                .collect(),
+            starts_at: "2024-01-01T00:00:00Z".to_string(),
+            ends_at: "2024-01-02T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            created_by: "test-user".to_string(),
+            comment: "Test comment".to_string(),
+        };
+
+        let blocks = build_silence_blocks(&silence);
+        let section_count = blocks
+            .iter()
+            .filter(|b| matches!(b, SlackBlock::Section { .. }))
+            .count();
+        assert!(section_count > 1);
+        assert!(matches!(blocks.last(), Some(SlackBlock::Divider {})));
+    }
+
+    #[test]
+    fn test_build_silence_blocks_truncates_multi_byte_comment_without_panicking() {
+        let comment = "a".repeat(COMMENT_PREVIEW_CHARS - 1) + "日本語テスト";
+        let silence = Silence {
+            id: "test-id-multibyte".to_string(),
+            status: SilenceStatus {
+                state: "active".to_string(),
+            },
+            matchers: vec![],
+            starts_at: "2024-01-01T00:00:00Z".to_string(),
+            ends_at: "2024-01-02T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            created_by: "test-user".to_string(),
+            comment,
+        };
+
+        let blocks = build_silence_blocks(&silence);
+        assert!(!blocks.is_empty());
+    }
+
+    #[test]
+    fn test_format_slack_messages_empty() {
+        let silences = vec![];
+        let messages = format_slack_messages(&silences, None);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].blocks.len() >= 3);
+    }
+
+    #[test]
+    fn test_format_slack_messages_with_data() {
+        let silences = vec![Silence {
+            id: "test-id-123".to_string(),
+            status: SilenceStatus {
+                state: "active".to_string(),
+            },
+            matchers: vec![Matcher {
+                name: "alertname".to_string(),
+                value: "TestAlert".to_string(),
+                is_regex: false,
+                is_equal: true,
+            }],
+            starts_at: "2024-01-01T00:00:00Z".to_string(),
+            ends_at: "2024-01-02T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            created_by: "test-user".to_string(),
+            comment: "Test comment".to_string(),
+        }];
+
+        let messages = format_slack_messages(&silences, None);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].blocks.len() > 3);
+    }
+
+    #[test]
+    fn test_format_slack_messages_multiple_parts() {
+        // Create 50 silences to test message splitting
+        let silences: Vec<Silence> = (0..50)
+            .map(|i| Silence {
+                id: format!("test-id-{}", i),
+                status: SilenceStatus {
+                    state: "active".to_string(),
+                },
+                matchers: vec![Matcher {
+                    name: "alertname".to_string(),
+                    value: format!("TestAlert{}", i),
+                    is_regex: false,
+                    is_equal: true,
+                }],
+                starts_at: "2024-01-01T00:00:00Z".to_string(),
+                ends_at: "2024-01-02T00:00:00Z".to_string(),
+                updated_at: "2024-01-01T00:00:00Z".to_string(),
+                created_by: "test-user".to_string(),
+                comment: "Test comment".to_string(),
+            })
+            .collect();
+
+        let messages = format_slack_messages(&silences, None);
+        assert_eq!(messages.len(), 3); // 50 silences = 100 blocks, packed 47 + 48 + 5
+    }
+
+    #[test]
+    fn test_format_slack_messages_splits_a_single_oversized_silence_across_messages() {
+        // One silence with enough matchers that its own blocks (chunked
+        // sections + divider) alone overflow a single message's budget.
+        let silence = Silence {
+            id: "test-id-huge".to_string(),
+            status: SilenceStatus {
+                state: "active".to_string(),
+            },
+            matchers: (0..6000)
+                .map(|i| Matcher {
+                    name: format!("label{}", i),
+                    value: "x".repeat(20),
+                    is_regex: false,
+                    is_equal: true,
+                })
+                .collect(),
+            starts_at: "2024-01-01T00:00:00Z".to_string(),
+            ends_at: "2024-01-02T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            created_by: "test-user".to_string(),
+            comment: "Test comment".to_string(),
+        };
+
+        let messages = format_slack_messages(&[silence], None);
+        assert!(messages.len() > 1);
+        for message in &messages {
+            assert!(message.blocks.len() <= MAX_BLOCKS_PER_MESSAGE);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::{render_report, ReportSink};
+use crate::model::{Silence, SilenceDiff};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+struct SendMessageRequest {
+    chat_id: String,
+    text: String,
+    parse_mode: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+struct SendMessageResponse {
+    ok: bool,
+    description: Option<String>,
+}
+
+/// Delivers a silences report as a MarkdownV2 message via the Telegram Bot
+/// API's `sendMessage` method. `render_report` escapes every
+/// Alertmanager-controlled field it interpolates, since Telegram rejects
+/// the whole request with a 400 if the text contains an unescaped
+/// MarkdownV2 special character.
+pub struct TelegramSink {
+    pub bot_token: String,
+    pub chat_id: String,
+}
+
+impl ReportSink for TelegramSink {
+    fn send(&self, silences: &[Silence], diff: Option<&SilenceDiff>) -> Result<()> {
+        let client = reqwest::blocking::Client::new();
+        let url = format!("https://api.telegram.org/bot{}/sendMessage", self.bot_token);
+
+        let request = SendMessageRequest {
+            chat_id: self.chat_id.clone(),
+            text: render_report(silences, diff),
+            parse_mode: "MarkdownV2",
+        };
+
+        let response = client
+            .post(&url)
+            .json(&request)
+            .send()
+            .context("Failed to send message to Telegram Bot API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!("Telegram API returned error status {}: {}", status, body);
+        }
+
+        let telegram_response: SendMessageResponse = response
+            .json()
+            .context("Failed to parse Telegram API response")?;
+
+        if !telegram_response.ok {
+            anyhow::bail!(
+                "Telegram API returned error: {}",
+                telegram_response
+                    .description
+                    .unwrap_or_else(|| "unknown error".to_string())
+            );
+        }
+
+        println!("Sent {} silence(s) to Telegram", silences.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use super::ReportSink;
+use crate::model::{Silence, SilenceDiff};
+use anyhow::{Context, Result};
+
+/// Delivers a silences report by POSTing the silences as a JSON array to
+/// an arbitrary URL.
+pub struct WebhookSink {
+    pub url: String,
+}
+
+impl ReportSink for WebhookSink {
+    fn send(&self, silences: &[Silence], _diff: Option<&SilenceDiff>) -> Result<()> {
+        let client = reqwest::blocking::Client::new();
+
+        let response = client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .json(silences)
+            .send()
+            .context("Failed to send silences to webhook")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!("Webhook returned error status {}: {}", status, body);
+        }
+
+        println!("Sent {} silence(s) to webhook", silences.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::model::{Silence, SilenceDiff};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// `Silence.id` -> `Silence.updated_at`, used to detect additions, removals
+/// and changes between daemon ticks without re-posting unchanged reports.
+pub type Fingerprints = HashMap<String, String>;
+
+pub fn fingerprint_silences(silences: &[Silence]) -> Fingerprints {
+    silences
+        .iter()
+        .map(|s| (s.id.clone(), s.updated_at.clone()))
+        .collect()
+}
+
+/// Loads the fingerprint store from `path`, treating a missing file as an
+/// empty store (e.g. the first daemon run).
+pub fn load_fingerprints(path: &Path) -> Result<Fingerprints> {
+    if !path.exists() {
+        return Ok(Fingerprints::new());
+    }
+
+    let data = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state file: {}", path.display()))?;
+
+    serde_json::from_str(&data)
+        .with_context(|| format!("Failed to parse state file: {}", path.display()))
+}
+
+pub fn save_fingerprints(path: &Path, fingerprints: &Fingerprints) -> Result<()> {
+    let data = serde_json::to_string_pretty(fingerprints).context("Failed to serialize state")?;
+
+    std::fs::write(path, data)
+        .with_context(|| format!("Failed to write state file: {}", path.display()))
+}
+
+/// Compares the previous and current fingerprint sets, counting silences
+/// that are new, gone, or present in both but with a different `updated_at`.
+pub fn diff_fingerprints(previous: &Fingerprints, current: &Fingerprints) -> SilenceDiff {
+    let mut diff = SilenceDiff::default();
+
+    for (id, updated_at) in current {
+        match previous.get(id) {
+            None => diff.added += 1,
+            Some(prev_updated_at) if prev_updated_at != updated_at => diff.changed += 1,
+            _ => {}
+        }
+    }
+
+    diff.removed = previous.keys().filter(|id| !current.contains_key(*id)).count();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_fingerprints_detects_added_removed_changed() {
+        let previous: Fingerprints = [
+            ("1".to_string(), "2024-01-01T00:00:00Z".to_string()),
+            ("2".to_string(), "2024-01-01T00:00:00Z".to_string()),
+        ]
+        .into_iter()
+        .collect();
+
+        let current: Fingerprints = [
+            ("1".to_string(), "2024-01-02T00:00:00Z".to_string()),
+            ("3".to_string(), "2024-01-01T00:00:00Z".to_string()),
+        ]
+        .into_iter()
+        .collect();
+
+        let diff = diff_fingerprints(&previous, &current);
+        assert_eq!(diff.added, 1);
+        assert_eq!(diff.removed, 1);
+        assert_eq!(diff.changed, 1);
+        assert!(diff.has_changes());
+    }
+
+    #[test]
+    fn test_diff_fingerprints_no_changes() {
+        let fingerprints: Fingerprints = [("1".to_string(), "2024-01-01T00:00:00Z".to_string())]
+            .into_iter()
+            .collect();
+
+        let diff = diff_fingerprints(&fingerprints, &fingerprints);
+        assert!(!diff.has_changes());
+    }
+}